@@ -99,4 +99,4 @@
 
 mod parser;
 
-pub use parser::{RobotsTxt, RobotRule};
\ No newline at end of file
+pub use parser::{Mode, RobotRule, RobotsTxt, UnexpectedStatusError};
\ No newline at end of file
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::fmt;
 
 #[cfg(feature = "async")]
 use reqwest;
@@ -12,8 +13,43 @@ pub struct RobotRule {
     pub allowed: Vec<String>,
     /// List of paths disallowed for this user-agent
     pub disallowed: Vec<String>,
+    /// The `Crawl-delay` directive for this user-agent, in seconds, if present
+    pub crawl_delay: Option<f64>,
+    /// The `Request-rate` directive for this user-agent, as `(requests, seconds)`, if present
+    pub request_rate: Option<(u32, u32)>,
 }
 
+/// The access mode a `RobotsTxt` was parsed into, per RFC 9309's status-code
+/// semantics: a missing or erroring robots.txt does not mean "no rules", it
+/// means "allow everything" or "disallow everything" until the file can be
+/// fetched successfully.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// The content was parsed into rules (a string passed to `parse`, or a 2xx response body).
+    Parsed,
+    /// robots.txt was unavailable (4xx); the whole site is fully allowed.
+    AllowAll,
+    /// robots.txt could not be served (5xx); the whole site is fully disallowed.
+    DisallowAll,
+}
+
+/// Error returned when a robots.txt HTTP status code can't be mapped to an
+/// access mode under RFC 9309 (i.e. it's not 2xx, 4xx, or 5xx).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnexpectedStatusError(pub u16);
+
+impl fmt::Display for UnexpectedStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unexpected robots.txt HTTP status code: {}",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for UnexpectedStatusError {}
+
 /// The main structure representing a parsed robots.txt file.
 #[derive(Debug)]
 pub struct RobotsTxt {
@@ -21,10 +57,10 @@ pub struct RobotsTxt {
     rules: HashMap<String, RobotRule>,
     sitemaps: Vec<String>,
     comments: Vec<String>,
+    mode: Mode,
+    host: Option<String>,
 }
 
-#[cfg(feature = "async")]
-
 impl RobotsTxt {
     /// Parse a robots.txt file from a string.
     ///
@@ -63,8 +99,10 @@ impl RobotsTxt {
         let mut rules: HashMap<String, RobotRule> = HashMap::new();
         let mut sitemaps = Vec::new();
         let mut comments = Vec::new();
+        let mut host = None;
 
         let mut current_agents: Vec<String> = Vec::new();
+        let mut seen_rule_since_agent = false;
 
         for line in content.lines() {
             let line = line.trim();
@@ -87,7 +125,14 @@ impl RobotsTxt {
 
                 match directive.as_str() {
                     "user-agent" => {
-                        // Start new user-agent group
+                        // A user-agent line that follows a rule starts a fresh group;
+                        // one that directly follows another user-agent line joins the
+                        // same group, so consecutive agents share the rules below them.
+                        if seen_rule_since_agent {
+                            current_agents.clear();
+                            seen_rule_since_agent = false;
+                        }
+
                         let agent = value.to_lowercase();
                         if !rules.contains_key(&agent) {
                             rules.insert(
@@ -96,6 +141,8 @@ impl RobotsTxt {
                                     user_agent: agent.clone(),
                                     allowed: Vec::new(),
                                     disallowed: Vec::new(),
+                                    crawl_delay: None,
+                                    request_rate: None,
                                 },
                             );
                         }
@@ -108,6 +155,7 @@ impl RobotsTxt {
                                 rule.allowed.push(value.clone());
                             }
                         }
+                        seen_rule_since_agent = true;
                     }
                     "disallow" => {
                         // Add to all current agents
@@ -116,11 +164,36 @@ impl RobotsTxt {
                                 rule.disallowed.push(value.clone());
                             }
                         }
+                        seen_rule_since_agent = true;
+                    }
+                    "crawl-delay" => {
+                        if let Ok(delay) = value.parse::<f64>() {
+                            for agent in &current_agents {
+                                if let Some(rule) = rules.get_mut(agent) {
+                                    rule.crawl_delay = Some(delay);
+                                }
+                            }
+                        }
+                        seen_rule_since_agent = true;
+                    }
+                    "request-rate" => {
+                        if let Some(rate) = parse_request_rate(&value) {
+                            for agent in &current_agents {
+                                if let Some(rule) = rules.get_mut(agent) {
+                                    rule.request_rate = Some(rate);
+                                }
+                            }
+                        }
+                        seen_rule_since_agent = true;
+                    }
+                    "host" => {
+                        // Host is global and group-independent, like sitemap.
+                        host = Some(value);
                     }
                     "sitemap" => {
+                        // Sitemap is global and group-independent: it doesn't end
+                        // the current user-agent group.
                         sitemaps.push(value);
-                        // Sitemap is global, reset current agents
-                        current_agents.clear();
                     }
                     _ => {
                         // Unknown directive, could log or ignore
@@ -134,11 +207,66 @@ impl RobotsTxt {
             rules,
             sitemaps,
             comments,
+            mode: Mode::Parsed,
+            host,
+        }
+    }
+
+    /// Build a `RobotsTxt` from an HTTP response body and status code, applying
+    /// RFC 9309's status-code semantics.
+    ///
+    /// A 2xx status parses `content` as usual. A 4xx status means robots.txt is
+    /// unavailable, so the whole site is treated as fully allowed. A 5xx status
+    /// means the server failed to serve it, so the whole site is treated as
+    /// fully disallowed until it can be fetched again. Any other status code is
+    /// not covered by RFC 9309 and is surfaced as an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The robots.txt file content as a string
+    /// * `status_code` - The HTTP status code the content was fetched with
+    /// * `domain` - Optional domain name to associate with this robots.txt
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use robotstxt_rs::RobotsTxt;
+    ///
+    /// let robots = RobotsTxt::parse_with_status("", 404, None).unwrap();
+    /// assert!(robots.can_fetch("Mozilla", "/anything"));
+    /// ```
+    pub fn parse_with_status(
+        content: &str,
+        status_code: u16,
+        domain: Option<String>,
+    ) -> Result<Self, UnexpectedStatusError> {
+        match status_code {
+            200..=299 => Ok(Self::parse_with_domain(content, domain)),
+            400..=499 => Ok(RobotsTxt {
+                domain,
+                rules: HashMap::new(),
+                sitemaps: Vec::new(),
+                comments: Vec::new(),
+                mode: Mode::AllowAll,
+                host: None,
+            }),
+            500..=599 => Ok(RobotsTxt {
+                domain,
+                rules: HashMap::new(),
+                sitemaps: Vec::new(),
+                comments: Vec::new(),
+                mode: Mode::DisallowAll,
+                host: None,
+            }),
+            other => Err(UnexpectedStatusError(other)),
         }
     }
 
     /// Fetch and parse a robots.txt file from a URL (requires async feature).
     ///
+    /// The response's HTTP status code is used to pick an access mode per
+    /// RFC 9309: see [`RobotsTxt::parse_with_status`] for the exact semantics.
+    ///
     /// # Arguments
     ///
     /// * `url` - The URL to the robots.txt file
@@ -154,14 +282,17 @@ impl RobotsTxt {
     ///     Ok(())
     /// }
     /// ```
+    #[cfg(feature = "async")]
     pub async fn from_url(url: &str) -> Result<Self, Box<dyn std::error::Error>> {
         let client = reqwest::Client::new();
-        let content = client.get(url).send().await?.text().await?;
+        let response = client.get(url).send().await?;
+        let status_code = response.status().as_u16();
+        let content = response.text().await?;
 
         // Extract domain from URL
         let domain = extract_domain(url);
 
-        Ok(Self::parse_with_domain(&content, Some(domain)))
+        Ok(Self::parse_with_status(&content, status_code, Some(domain))?)
     }
 
     /// Check if a user-agent is allowed to fetch a specific path.
@@ -186,49 +317,65 @@ impl RobotsTxt {
     /// assert!(robots.can_fetch("Googlebot", "/public/page"));
     /// ```
     pub fn can_fetch(&self, user_agent: &str, path: &str) -> bool {
-        let user_agent = user_agent.to_lowercase();
-
-        // Try exact match first
-        let rule = if let Some(rule) = self.rules.get(&user_agent) {
-            rule
-        } else if let Some(rule) = self.rules.get("*") {
-            // Fall back to wildcard
-            rule
-        } else {
+        match self.mode {
+            Mode::AllowAll => return true,
+            Mode::DisallowAll => return false,
+            Mode::Parsed => {}
+        }
+
+        let rule = match select_rule(&self.rules, user_agent) {
+            Some(rule) => rule,
             // No rules = allowed
-            return true;
+            None => return true,
         };
 
-        // Check disallowed paths first (more restrictive)
-        for disallowed in &rule.disallowed {
-            if disallowed.is_empty() {
-                continue;
-            }
-            if path_matches(path, disallowed) {
-                // Check if there's a more specific allow rule
-                for allowed in &rule.allowed {
-                    if path_matches(path, allowed) && allowed.len() > disallowed.len() {
-                        return true;
-                    }
-                }
-                return false;
-            }
+        match longest_match(path, rule) {
+            Match::Allow | Match::None => true,
+            Match::Disallow => false,
         }
+    }
 
-        // If not explicitly disallowed, check allowed rules
-        // Empty allowed list means everything is allowed
-        if rule.allowed.is_empty() {
-            return true;
+    /// Check if a path is explicitly disallowed for a user-agent, as opposed to
+    /// merely unmentioned.
+    ///
+    /// Unlike `can_fetch`, which treats an unmentioned path as allowed, this
+    /// distinguishes "allowed because a rule permits it or there's nothing
+    /// overriding the default" from "blocked by an explicit `Disallow`". It
+    /// shares the same longest-match precedence as `can_fetch`, so a `Disallow`
+    /// overridden by a longer (or equal) `Allow` is not considered explicit.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_agent` - The user-agent string (e.g., "Googlebot")
+    /// * `path` - The path to check (e.g., "/admin/panel")
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` only if `path` is matched by a `Disallow` pattern that
+    /// isn't overridden by a longer or equal `Allow`; `false` if it's
+    /// unmentioned or permitted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use robotstxt_rs::RobotsTxt;
+    ///
+    /// let content = "User-agent: *\nDisallow: /admin/";
+    /// let robots = RobotsTxt::parse(content);
+    /// assert!(robots.is_explicitly_disallowed("Googlebot", "/admin/panel"));
+    /// assert!(!robots.is_explicitly_disallowed("Googlebot", "/public/page"));
+    /// ```
+    pub fn is_explicitly_disallowed(&self, user_agent: &str, path: &str) -> bool {
+        if self.mode != Mode::Parsed {
+            return false;
         }
 
-        for allowed in &rule.allowed {
-            if path_matches(path, allowed) {
-                return true;
-            }
-        }
+        let rule = match select_rule(&self.rules, user_agent) {
+            Some(rule) => rule,
+            None => return false,
+        };
 
-        // If there are allow rules but no match, it's disallowed
-        false
+        longest_match(path, rule) == Match::Disallow
     }
 
     /// Get the domain associated with this robots.txt file.
@@ -269,6 +416,12 @@ impl RobotsTxt {
 
     /// Get the rule for a specific user-agent.
     ///
+    /// Matching follows RFC 9309: `user_agent` is reduced to its product token
+    /// (the part before any `/` or whitespace, e.g. `"Googlebot"` out of
+    /// `"Googlebot/2.1 (+http://www.google.com/bot.html)"`), and the group whose
+    /// declared agent name is the longest case-insensitive prefix of that token
+    /// is selected, falling back to the wildcard (`*`) group if none match.
+    ///
     /// # Arguments
     ///
     /// * `user_agent` - The user-agent string to look up
@@ -289,11 +442,56 @@ impl RobotsTxt {
     /// }
     /// ```
     pub fn get_rule(&self, user_agent: &str) -> Option<&RobotRule> {
-        let user_agent = user_agent.to_lowercase();
-        self.rules.get(&user_agent).or_else(|| self.rules.get("*"))
+        select_rule(&self.rules, user_agent)
+    }
+
+    /// Get the host declared for this robots.txt file via the `Host:` directive.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(&str)` if a `Host:` directive was present, `None` otherwise.
+    pub fn get_host(&self) -> Option<&str> {
+        self.host.as_deref()
+    }
+
+    /// Get the `Crawl-delay` (in seconds) for a specific user-agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_agent` - The user-agent string to look up
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(f64)` if a `Crawl-delay` was declared for this user-agent or
+    /// the wildcard (*), `None` otherwise.
+    pub fn get_crawl_delay(&self, user_agent: &str) -> Option<f64> {
+        self.get_rule(user_agent).and_then(|rule| rule.crawl_delay)
+    }
+
+    /// Get the `Request-rate` (requests per seconds window) for a specific user-agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_agent` - The user-agent string to look up
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some((requests, seconds))` if a `Request-rate` was declared for this
+    /// user-agent or the wildcard (*), `None` otherwise.
+    pub fn get_request_rate(&self, user_agent: &str) -> Option<(u32, u32)> {
+        self.get_rule(user_agent).and_then(|rule| rule.request_rate)
     }
 }
 
+/// Parse a `Request-rate` value such as `1/5` (1 request per 5 seconds) into
+/// `(requests, seconds)`.
+fn parse_request_rate(value: &str) -> Option<(u32, u32)> {
+    let (requests, seconds) = value.split_once('/')?;
+    let requests = requests.trim().parse().ok()?;
+    let seconds = seconds.trim().parse().ok()?;
+    Some((requests, seconds))
+}
+
 fn extract_domain(url: &str) -> String {
     // Simple domain extraction - handles common cases
     let url = url.trim();
@@ -313,6 +511,99 @@ fn extract_domain(url: &str) -> String {
     domain.to_string()
 }
 
+/// Reduce a user-agent string to its product token: the part before any `/`
+/// or whitespace, lower-cased (e.g. `"Googlebot/2.1 (+http://...)"` -> `"googlebot"`).
+fn product_token(user_agent: &str) -> String {
+    let token = user_agent
+        .split(|c: char| c == '/' || c.is_whitespace())
+        .next()
+        .unwrap_or("");
+    token.to_lowercase()
+}
+
+/// Select the rule group matching `user_agent` per RFC 9309: the group whose
+/// declared agent name is the longest case-insensitive prefix of the caller's
+/// product token, falling back to the wildcard (`*`) group if none match.
+fn select_rule<'a>(rules: &'a HashMap<String, RobotRule>, user_agent: &str) -> Option<&'a RobotRule> {
+    let token = product_token(user_agent);
+
+    let mut best: Option<(&str, usize)> = None;
+    for key in rules.keys() {
+        if key == "*" {
+            continue;
+        }
+        if token.starts_with(key.as_str()) {
+            let len = key.len();
+            let is_longer = match best {
+                None => true,
+                Some((_, blen)) => len > blen,
+            };
+            if is_longer {
+                best = Some((key, len));
+            }
+        }
+    }
+
+    match best {
+        Some((key, _)) => rules.get(key),
+        None => rules.get("*"),
+    }
+}
+
+/// The outcome of matching a path against a rule's `allow`/`disallow`
+/// patterns using RFC 9309's most-specific-match precedence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Match {
+    /// The longest matching pattern was an `Allow`.
+    Allow,
+    /// The longest matching pattern was a `Disallow`.
+    Disallow,
+    /// No pattern in the rule matched the path.
+    None,
+}
+
+/// Find the longest `allow`/`disallow` pattern in `rule` that matches `path`,
+/// per RFC 9309 (Google's "most specific match" algorithm): among all
+/// patterns that match, the one with the most characters wins, and an
+/// `Allow` wins a length tie against a `Disallow`.
+fn longest_match(path: &str, rule: &RobotRule) -> Match {
+    let mut best_len: Option<usize> = None;
+    let mut best = Match::None;
+
+    for disallowed in &rule.disallowed {
+        if disallowed.is_empty() {
+            continue;
+        }
+        if path_matches(path, disallowed) {
+            let len = disallowed.len();
+            let is_longer = match best_len {
+                None => true,
+                Some(blen) => len > blen,
+            };
+            if is_longer {
+                best_len = Some(len);
+                best = Match::Disallow;
+            }
+        }
+    }
+
+    for allowed in &rule.allowed {
+        if path_matches(path, allowed) {
+            let len = allowed.len();
+            let is_longer_or_tied = match best_len {
+                None => true,
+                Some(blen) => len >= blen,
+            };
+            if is_longer_or_tied {
+                best_len = Some(len);
+                best = Match::Allow;
+            }
+        }
+    }
+
+    best
+}
+
 fn path_matches(path: &str, pattern: &str) -> bool {
     // Handle end-of-string anchor $
     if pattern.ends_with('$') {
@@ -397,6 +688,169 @@ Sitemap: https://example.com/sitemap.xml
         assert!(!path_matches("/test.html/more", "/test.html$"));
     }
 
+    #[test]
+    fn test_stacked_user_agents_share_rules() {
+        let content = r#"
+User-agent: Googlebot
+User-agent: *
+Disallow: /private/
+        "#;
+        let robots = RobotsTxt::parse(content);
+
+        assert!(!robots.can_fetch("Googlebot", "/private/data"));
+        assert!(!robots.can_fetch("Mozilla", "/private/data"));
+    }
+
+    #[test]
+    fn test_new_agent_after_rule_starts_fresh_group() {
+        let content = r#"
+User-agent: Googlebot
+Disallow: /private/
+
+User-agent: Bingbot
+Disallow: /secret/
+        "#;
+        let robots = RobotsTxt::parse(content);
+
+        // Each agent only inherited the rules declared after its own group started.
+        assert!(robots.can_fetch("Googlebot", "/secret/data"));
+        assert!(robots.can_fetch("Bingbot", "/private/data"));
+        assert!(!robots.can_fetch("Googlebot", "/private/data"));
+        assert!(!robots.can_fetch("Bingbot", "/secret/data"));
+    }
+
+    #[test]
+    fn test_user_agent_longest_prefix_wins() {
+        let content = r#"
+User-agent: googlebot
+Disallow: /no-news/
+
+User-agent: googlebot-news
+Disallow: /no-general/
+        "#;
+        let robots = RobotsTxt::parse(content);
+
+        // "googlebot-news" is a longer, more specific match than "googlebot".
+        assert!(!robots.can_fetch("Googlebot-News/1.0", "/no-general/x"));
+        assert!(robots.can_fetch("Googlebot-News/1.0", "/no-news/x"));
+
+        // Plain "Googlebot" only matches the less specific group.
+        assert!(!robots.can_fetch("Googlebot/2.1", "/no-news/x"));
+        assert!(robots.can_fetch("Googlebot/2.1", "/no-general/x"));
+    }
+
+    #[test]
+    fn test_sitemap_does_not_break_user_agent_group() {
+        let content = r#"
+User-agent: *
+Sitemap: https://example.com/sitemap.xml
+Disallow: /private/
+        "#;
+        let robots = RobotsTxt::parse(content);
+
+        assert!(!robots.can_fetch("Mozilla", "/private/data"));
+        assert_eq!(robots.get_sitemaps().len(), 1);
+    }
+
+    #[test]
+    fn test_user_agent_product_token_matching() {
+        let content = "User-agent: Googlebot\nDisallow: /private/";
+        let robots = RobotsTxt::parse(content);
+
+        // A full product-plus-comment UA string should still match the declared token.
+        assert!(!robots.can_fetch(
+            "Googlebot/2.1 (+http://www.google.com/bot.html)",
+            "/private/data"
+        ));
+        assert!(robots.can_fetch(
+            "Googlebot/2.1 (+http://www.google.com/bot.html)",
+            "/public/data"
+        ));
+    }
+
+    #[test]
+    fn test_is_explicitly_disallowed() {
+        let content = "User-agent: *\nDisallow: /\nAllow: /allow.html";
+        let robots = RobotsTxt::parse(content);
+
+        // Overridden by a longer Allow: not explicitly disallowed.
+        assert!(!robots.is_explicitly_disallowed("Mozilla", "/allow.html"));
+        // Matched only by the Disallow: explicitly disallowed.
+        assert!(robots.is_explicitly_disallowed("Mozilla", "/other"));
+
+        // Unmentioned paths are never explicitly disallowed, even with no rules at all.
+        let empty = RobotsTxt::parse("");
+        assert!(!empty.is_explicitly_disallowed("Mozilla", "/anything"));
+    }
+
+    #[test]
+    fn test_crawl_delay_request_rate_and_host() {
+        let content = r#"
+User-agent: *
+Crawl-delay: 10
+Request-rate: 1/5
+Disallow: /admin/
+
+Host: example.com
+        "#;
+
+        let robots = RobotsTxt::parse(content);
+
+        assert_eq!(robots.get_crawl_delay("Mozilla"), Some(10.0));
+        assert_eq!(robots.get_request_rate("Mozilla"), Some((1, 5)));
+        assert_eq!(robots.get_host(), Some("example.com"));
+    }
+
+    #[test]
+    fn test_can_fetch_longest_match_precedence() {
+        let content = "User-agent: *\nDisallow: /\nAllow: /allow.html";
+        let robots = RobotsTxt::parse(content);
+
+        // Table of (path, expected) covering the standard most-specific-match cases.
+        let cases = [
+            ("/allow.html", true),
+            ("/allow.html/more", true),
+            ("/", false),
+            ("/other", false),
+        ];
+
+        for (path, expected) in cases {
+            assert_eq!(
+                robots.can_fetch("Mozilla", path),
+                expected,
+                "path {} should be allowed={}",
+                path,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_can_fetch_equal_length_tie_favors_allow() {
+        let content = "User-agent: *\nDisallow: /page\nAllow: /page";
+        let robots = RobotsTxt::parse(content);
+        assert!(robots.can_fetch("Mozilla", "/page"));
+    }
+
+    #[test]
+    fn test_status_code_semantics() {
+        // 2xx parses the body as usual.
+        let ok = RobotsTxt::parse_with_status("User-agent: *\nDisallow: /admin/", 200, None)
+            .unwrap();
+        assert!(!ok.can_fetch("Mozilla", "/admin/panel"));
+
+        // 4xx means robots.txt is unavailable: fully allowed.
+        let not_found = RobotsTxt::parse_with_status("", 404, None).unwrap();
+        assert!(not_found.can_fetch("Mozilla", "/admin/panel"));
+
+        // 5xx means the server failed to serve it: fully disallowed.
+        let server_error = RobotsTxt::parse_with_status("", 503, None).unwrap();
+        assert!(!server_error.can_fetch("Mozilla", "/public/page"));
+
+        // Anything else isn't covered by RFC 9309.
+        assert!(RobotsTxt::parse_with_status("", 301, None).is_err());
+    }
+
     #[test]
     fn test_domain_extraction() {
         assert_eq!(